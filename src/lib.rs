@@ -1,10 +1,16 @@
 use std::{
+    collections::HashMap,
     error::Error,
     fmt::{Display, Formatter},
+    pin::Pin,
+    sync::{Arc, RwLock},
 };
 
 use async_trait::async_trait;
-use tokio::{fs, fs::File, io::AsyncReadExt, io::AsyncWriteExt};
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use tokio::{fs, fs::File, io::AsyncRead, io::AsyncReadExt, io::AsyncWriteExt};
+use tokio_util::io::ReaderStream;
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
@@ -56,6 +62,106 @@ pub trait Files: Send + Sync {
     /// }
     /// ```
     async fn write(&self, path: &str, content: &str) -> Result<(), WriteError>;
+    /// Reads the content of a file at the specified path as a stream of chunks,
+    /// without buffering the whole file in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that represents the path to the file to be read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use filez::{live, Files};
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let files = live("path/to/root".to_string());
+    ///     let mut stream = files.read_stream("path/to/file.bin").await.unwrap();
+    ///     while let Some(chunk) = stream.next().await {
+    ///         let chunk = chunk.unwrap();
+    ///         println!("read {} bytes", chunk.len());
+    ///     }
+    /// }
+    /// ```
+    async fn read_stream(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = std::io::Result<Bytes>> + Send>>, ReadError>;
+    /// Writes the content of `reader` to a file at the specified path, copying it
+    /// chunk-by-chunk instead of buffering the whole content in memory.
+    /// If the directory does not exist, it will be created.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that represents the path to the file to be written.
+    /// * `reader` - An `AsyncRead` that produces the content to be written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created or the file cannot be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use filez::{live, Files};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let files = live("path/to/root".to_string());
+    ///     let reader = tokio::io::empty();
+    ///     files.save_async_read("path/to/file.bin", reader).await.unwrap();
+    /// }
+    /// ```
+    async fn save_async_read<R>(&self, path: &str, reader: R) -> Result<(), WriteError>
+    where
+        R: AsyncRead + Unpin + Send + 'static;
+    /// Writes the specified content to a file at the specified path, failing if a file
+    /// already exists there. Use this for content-addressed storage where an existing
+    /// file must never be silently overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that represents the path to the file to be written.
+    /// * `content` - A string slice that represents the content to be written to the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WriteErrorKind::AlreadyExists`] if a file already exists at `path`, or
+    /// another error if the directory cannot be created or the file cannot be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use filez::{live, Files};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let files = live("path/to/root".to_string());
+    ///     files.write_new("path/to/file.txt", "Hello, world!").await.unwrap();
+    /// }
+    /// ```
+    async fn write_new(&self, path: &str, content: &str) -> Result<(), WriteError>;
+    /// Writes the content of `reader` to a file at the specified path, failing if a
+    /// file already exists there. See [`Files::write_new`] and [`Files::save_async_read`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that represents the path to the file to be written.
+    /// * `reader` - An `AsyncRead` that produces the content to be written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WriteErrorKind::AlreadyExists`] if a file already exists at `path`, or
+    /// another error if the directory cannot be created or the file cannot be written to.
+    async fn save_async_read_new<R>(&self, path: &str, reader: R) -> Result<(), WriteError>
+    where
+        R: AsyncRead + Unpin + Send + 'static;
     /// Lists all files that match the specified glob expression.
     ///
     /// # Arguments
@@ -79,13 +185,228 @@ pub trait Files: Send + Sync {
     /// }
     ///
     fn list(&self, expresson: &str) -> Result<Vec<String>, ListError>;
+    /// Creates a directory at the specified path, creating parent directories
+    /// as needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that represents the path to the directory to create.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use filez::{live, Files};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let files = live("path/to/root".to_string());
+    ///     files.create_dir("path/to/dir").await.unwrap();
+    /// }
+    /// ```
+    async fn create_dir(&self, path: &str) -> Result<(), CreateDirError>;
+    /// Removes the file at the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that represents the path to the file to remove.
+    /// * `options` - Controls whether a missing file is treated as success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be removed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use filez::{live, Files, RemoveOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let files = live("path/to/root".to_string());
+    ///     files.remove_file("path/to/file.txt", RemoveOptions::default()).await.unwrap();
+    /// }
+    /// ```
+    async fn remove_file(&self, path: &str, options: RemoveOptions) -> Result<(), RemoveError>;
+    /// Removes the directory at the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that represents the path to the directory to remove.
+    /// * `options` - Controls recursive removal and whether a missing directory is
+    ///   treated as success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be removed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use filez::{live, Files, RemoveOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let files = live("path/to/root".to_string());
+    ///     let options = RemoveOptions { recursive: true, ..RemoveOptions::default() };
+    ///     files.remove_dir("path/to/dir", options).await.unwrap();
+    /// }
+    /// ```
+    async fn remove_dir(&self, path: &str, options: RemoveOptions) -> Result<(), RemoveError>;
+    /// Copies the file at `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - A string slice that represents the path to the source file.
+    /// * `to` - A string slice that represents the path to the destination file.
+    /// * `options` - Controls whether an existing destination is overwritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot be read or the destination cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use filez::{live, Files, CopyOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let files = live("path/to/root".to_string());
+    ///     files.copy("a.txt", "b.txt", CopyOptions::default()).await.unwrap();
+    /// }
+    /// ```
+    async fn copy(&self, from: &str, to: &str, options: CopyOptions) -> Result<(), CopyError>;
+    /// Renames (moves) the file at `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - A string slice that represents the path to the source file.
+    /// * `to` - A string slice that represents the path to the destination file.
+    /// * `options` - Controls whether an existing destination is overwritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot be read or the destination cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use filez::{live, Files, RenameOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let files = live("path/to/root".to_string());
+    ///     files.rename("a.txt", "b.txt", RenameOptions::default()).await.unwrap();
+    /// }
+    /// ```
+    async fn rename(&self, from: &str, to: &str, options: RenameOptions)
+        -> Result<(), RenameError>;
+    /// Returns metadata for the entry at the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that represents the path to the entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry does not exist or its metadata cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use filez::{live, Files};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let files = live("path/to/root".to_string());
+    ///     let metadata = files.metadata("path/to/file.txt").await.unwrap();
+    ///     println!("{} bytes", metadata.size);
+    /// }
+    /// ```
+    async fn metadata(&self, path: &str) -> Result<Metadata, MetadataError>;
+    /// Watches `path` for changes, yielding batches of [`PathEvent`]s as files under it
+    /// are created, modified or removed.
+    ///
+    /// Events are debounced over a short interval, so a burst of writes to the same file
+    /// (e.g. an editor saving in several steps) is collapsed into one batch rather than
+    /// delivered as a flood of individual events.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that represents the path to watch, relative to the root.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying OS file watcher cannot be set up for `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use filez::{live, Files};
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let files = live("path/to/root".to_string());
+    ///     let mut changes = files.watch(".").await.unwrap();
+    ///     while let Some(events) = changes.next().await {
+    ///         println!("{events:?}");
+    ///     }
+    /// }
+    /// ```
+    async fn watch(&self, path: &str) -> Result<Pin<Box<dyn Stream<Item = Vec<PathEvent>> + Send>>, WatchError>;
+}
+
+/// Options controlling [`Files::remove_file`] and [`Files::remove_dir`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RemoveOptions {
+    /// For directories, remove their contents recursively instead of requiring
+    /// them to already be empty.
+    pub recursive: bool,
+    /// Treat a missing entry as success instead of returning an error.
+    pub ignore_if_not_exists: bool,
+}
+
+/// Options controlling [`Files::copy`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CopyOptions {
+    /// Overwrite the destination if it already exists.
+    pub overwrite: bool,
+}
+
+/// Options controlling [`Files::rename`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenameOptions {
+    /// Overwrite the destination if it already exists.
+    pub overwrite: bool,
+}
+
+/// Metadata about a file or directory, as returned by [`Files::metadata`].
+#[derive(Debug, Clone)]
+pub struct Metadata {
+    pub size: u64,
+    pub modified: std::time::SystemTime,
+    pub is_file: bool,
+    pub is_dir: bool,
 }
 
 #[derive(Debug)]
 #[non_exhaustive]
 pub struct ReadError {
     pub path: String,
-    pub source: std::io::Error,
+    pub kind: ReadErrorKind,
+}
+
+#[derive(Debug)]
+pub enum ReadErrorKind {
+    Io(std::io::Error),
+    /// `path` resolves outside the configured root, either lexically (e.g. via `..`
+    /// components) or, once resolved, through a symlink.
+    OutsideRoot,
 }
 
 impl Display for ReadError {
@@ -96,7 +417,10 @@ impl Display for ReadError {
 
 impl Error for ReadError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        Some(&self.source)
+        match &self.kind {
+            ReadErrorKind::Io(err) => Some(err),
+            ReadErrorKind::OutsideRoot => None,
+        }
     }
 }
 
@@ -104,7 +428,17 @@ impl Error for ReadError {
 #[non_exhaustive]
 pub struct WriteError {
     pub path: String,
-    pub source: std::io::Error,
+    pub kind: WriteErrorKind,
+}
+
+#[derive(Debug)]
+pub enum WriteErrorKind {
+    Io(std::io::Error),
+    /// A file already exists at the target path and the write was requested
+    /// in create-exclusive mode (see [`Files::write_new`]).
+    AlreadyExists,
+    /// `path` resolves outside the configured root, e.g. via `..` components.
+    OutsideRoot,
 }
 
 impl Display for WriteError {
@@ -115,7 +449,10 @@ impl Display for WriteError {
 
 impl Error for WriteError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        Some(&self.source)
+        match &self.kind {
+            WriteErrorKind::Io(err) => Some(err),
+            WriteErrorKind::AlreadyExists | WriteErrorKind::OutsideRoot => None,
+        }
     }
 }
 
@@ -130,6 +467,11 @@ pub struct ListError {
 pub enum ListErrorKind {
     ParseGlob(glob::PatternError),
     ReadPath(glob::GlobError),
+    /// `expression` resolves outside the configured root, e.g. via `..` components.
+    OutsideRoot,
+    /// The LIST request against a remote object-storage backend (`s3`/`gcs`/`azure`)
+    /// failed.
+    Io(std::io::Error),
 }
 
 impl Display for ListError {
@@ -143,87 +485,536 @@ impl Error for ListError {
         match &self.kind {
             ListErrorKind::ParseGlob(err) => Some(err),
             ListErrorKind::ReadPath(err) => Some(err),
+            ListErrorKind::OutsideRoot => None,
+            ListErrorKind::Io(err) => Some(err),
         }
     }
 }
 
-/// Creates a new instance of `ParentDirectory` that uses the specified parent directory.
-pub fn live(parent: String) -> impl Files {
-    ParentDirectory::new(parent)
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct CreateDirError {
+    pub path: String,
+    pub source: std::io::Error,
 }
 
-#[derive(Clone)]
-struct ParentDirectory {
-    pub parent: String,
+impl Display for CreateDirError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error creating directory `{}`", self.path)
+    }
 }
 
-impl ParentDirectory {
-    #[must_use]
-    pub const fn new(root: String) -> Self {
-        Self { parent: root }
+impl Error for CreateDirError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
     }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RemoveError {
+    pub path: String,
+    pub source: std::io::Error,
+}
 
-    fn with_parent(&self, path: &str) -> String {
-        format!("{}/{}", self.parent, path)
+impl Display for RemoveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error removing `{}`", self.path)
     }
 }
 
-#[async_trait]
-impl Files for ParentDirectory {
-    async fn read(&self, path: &str) -> Result<String, ReadError> {
-        let mut file = File::open(self.with_parent(path))
-            .await
-            .map_err(|source| ReadError {
-                path: path.to_string(),
-                source,
-            })?;
-        let mut content = String::new();
-        file.read_to_string(&mut content)
-            .await
-            .map_err(|source| ReadError {
-                path: path.to_string(),
-                source,
-            })?;
-        Ok(content)
+impl Error for RemoveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
     }
+}
 
-    async fn write(&self, path: &str, content: &str) -> Result<(), WriteError> {
-        let path_with_parent = self.with_parent(path);
-        let dir_path = std::path::Path::new(&path_with_parent)
-            .parent()
-            .ok_or_else(|| {
-                std::io::Error::new(std::io::ErrorKind::Other, "Could not get parent directory")
-            })
-            .map_err(|source| WriteError {
-                path: path.to_string(),
-                source,
-            })?;
-        fs::create_dir_all(dir_path)
-            .await
-            .map_err(|source| WriteError {
-                path: path.to_string(),
-                source,
-            })?;
-        let mut file = File::create(path_with_parent)
-            .await
-            .map_err(|source| WriteError {
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct CopyError {
+    pub from: String,
+    pub to: String,
+    pub source: std::io::Error,
+}
+
+impl Display for CopyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error copying `{}` to `{}`", self.from, self.to)
+    }
+}
+
+impl Error for CopyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RenameError {
+    pub from: String,
+    pub to: String,
+    pub source: std::io::Error,
+}
+
+impl Display for RenameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error renaming `{}` to `{}`", self.from, self.to)
+    }
+}
+
+impl Error for RenameError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct MetadataError {
+    pub path: String,
+    pub source: std::io::Error,
+}
+
+impl Display for MetadataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error reading metadata for `{}`", self.path)
+    }
+}
+
+impl Error for MetadataError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// A single file-system change reported by [`Files::watch`], carrying the path relative
+/// to the configured root (e.g. `dir/a.txt`). For the local backend this differs from
+/// [`Files::list`], whose matches are prefixed with the root directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathEvent {
+    Created(String),
+    Modified(String),
+    Removed(String),
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct WatchError {
+    pub path: String,
+    pub source: notify::Error,
+}
+
+impl Display for WatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error watching `{}`", self.path)
+    }
+}
+
+impl Error for WatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Creates a new instance of `ParentDirectory` that uses the specified parent directory.
+pub fn live(parent: String) -> impl Files {
+    ParentDirectory::new(parent)
+}
+
+/// Creates a new in-memory `Files` implementation, so downstream crates can unit-test
+/// logic that reads/writes/lists files without touching a real disk.
+#[must_use]
+pub fn memory() -> impl Files {
+    InMemory::default()
+}
+
+/// Failed to configure a remote object-storage backend, e.g. because the bucket/container
+/// name or its credentials are invalid.
+#[cfg(any(feature = "s3", feature = "gcs", feature = "azure"))]
+#[derive(Debug)]
+pub struct ObjectStoreConfigError(object_store::Error);
+
+#[cfg(any(feature = "s3", feature = "gcs", feature = "azure"))]
+impl Display for ObjectStoreConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to configure object store backend")
+    }
+}
+
+#[cfg(any(feature = "s3", feature = "gcs", feature = "azure"))]
+impl Error for ObjectStoreConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// Creates a `Files` implementation backed by an S3 (or S3-compatible) bucket, reading
+/// credentials and region from the environment the same way the AWS CLI/SDK does.
+///
+/// `prefix` is prepended to every path, so `read("a.txt")` fetches the `{prefix}/a.txt`
+/// object; this lets several logical roots share one bucket, analogous to how [`live`]
+/// scopes every operation to its `parent` directory.
+///
+/// # Errors
+///
+/// Returns an error if the bucket configuration is invalid.
+#[cfg(feature = "s3")]
+pub fn s3(bucket: String, prefix: String) -> Result<impl Files, ObjectStoreConfigError> {
+    let store = object_store::aws::AmazonS3Builder::from_env()
+        .with_bucket_name(bucket)
+        .build()
+        .map_err(ObjectStoreConfigError)?;
+    Ok(ObjectStoreFiles::new(Arc::new(store), prefix))
+}
+
+/// Creates a `Files` implementation backed by a Google Cloud Storage bucket, reading
+/// credentials from the environment the same way the GCS client libraries do.
+///
+/// `prefix` is prepended to every path; see [`s3`] for what that means for callers.
+///
+/// # Errors
+///
+/// Returns an error if the bucket configuration is invalid.
+#[cfg(feature = "gcs")]
+pub fn gcs(bucket: String, prefix: String) -> Result<impl Files, ObjectStoreConfigError> {
+    let store = object_store::gcp::GoogleCloudStorageBuilder::from_env()
+        .with_bucket_name(bucket)
+        .build()
+        .map_err(ObjectStoreConfigError)?;
+    Ok(ObjectStoreFiles::new(Arc::new(store), prefix))
+}
+
+/// Creates a `Files` implementation backed by an Azure Blob Storage container, reading
+/// credentials from the environment the same way the Azure client libraries do.
+///
+/// `prefix` is prepended to every path; see [`s3`] for what that means for callers.
+///
+/// # Errors
+///
+/// Returns an error if the container configuration is invalid.
+#[cfg(feature = "azure")]
+pub fn azure(container: String, prefix: String) -> Result<impl Files, ObjectStoreConfigError> {
+    let store = object_store::azure::MicrosoftAzureBuilder::from_env()
+        .with_container_name(container)
+        .build()
+        .map_err(ObjectStoreConfigError)?;
+    Ok(ObjectStoreFiles::new(Arc::new(store), prefix))
+}
+
+/// Marker error for [`ParentDirectory::with_parent`], indicating a caller-supplied
+/// path would lexically resolve outside the configured root.
+#[derive(Debug)]
+struct PathEscapesRoot;
+
+/// Infix `save_async_read`, `save_async_read_new` and `copy` use for their temporary
+/// files, which are then renamed or hard-linked into place (see
+/// [`ParentDirectory::temp_path`]). [`DebouncedWatch`] filters any path containing this
+/// out of the events it yields, so callers watching for real changes don't see filez's
+/// own temporary-file churn.
+const TEMP_PATH_MARKER: &str = ".filez-tmp-";
+
+/// Builds the `io::Error` used for operations whose error type has no dedicated
+/// `OutsideRoot` variant.
+fn path_escapes_root_error() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "path escapes the configured root",
+    )
+}
+
+/// Removes its temporary file on drop unless [`Self::disarm`] has already taken the
+/// path out. Guards the write-then-rename span in [`ParentDirectory::write_temp`] and
+/// its callers, so a failure partway through a write (the reader erroring mid-copy, a
+/// failed `fsync`, a failed final `rename`/`hard_link`) never leaves a stray
+/// `.filez-tmp-*` file behind.
+struct TempFileGuard(Option<String>);
+
+impl TempFileGuard {
+    const fn new(path: String) -> Self {
+        Self(Some(path))
+    }
+
+    fn path(&self) -> &str {
+        self.0.as_deref().expect("TempFileGuard path taken twice")
+    }
+
+    /// Takes the path back out, preventing `Drop` from removing it.
+    fn disarm(mut self) -> String {
+        self.0.take().expect("TempFileGuard path taken twice")
+    }
+}
+
+impl Drop for TempFileGuard {
+    fn drop(&mut self) {
+        if let Some(path) = self.0.take() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ParentDirectory {
+    pub parent: String,
+}
+
+impl ParentDirectory {
+    #[must_use]
+    pub const fn new(root: String) -> Self {
+        Self { parent: root }
+    }
+
+    /// Resolves `path` against the configured root, rejecting any path that
+    /// lexically escapes it (e.g. via `..` components or a leading `/`).
+    fn with_parent(&self, path: &str) -> Result<String, PathEscapesRoot> {
+        let normalized = Self::normalize_relative_path(path).ok_or(PathEscapesRoot)?;
+        Ok(format!("{}/{normalized}", self.parent))
+    }
+
+    /// Lexically resolves `.`/`..` components without touching the filesystem,
+    /// returning `None` if doing so would escape above the (implicit) root.
+    fn normalize_relative_path(path: &str) -> Option<String> {
+        if path.starts_with('/') {
+            return None;
+        }
+        let mut components = Vec::new();
+        for component in path.split('/') {
+            match component {
+                "" | "." => continue,
+                ".." => {
+                    components.pop()?;
+                }
+                other => components.push(other),
+            }
+        }
+        Some(components.join("/"))
+    }
+
+    /// Refuses a symlink that resolves outside the root, after the lexical jail in
+    /// [`Self::with_parent`] has already rejected `..`/absolute escapes.
+    ///
+    /// Walks up from `path_with_parent` to the nearest ancestor that currently exists
+    /// and canonicalizes *that*, rather than `path_with_parent` itself: a write/create/
+    /// remove target may not exist yet, but a symlink planted inside the root further
+    /// up the chain (e.g. `root/escape -> /tmp`) still resolves through canonicalizing
+    /// any existing ancestor, so this still catches the escape before anything is
+    /// opened, created, or removed through it.
+    async fn ensure_resolved_within_root(
+        &self,
+        path_with_parent: &str,
+    ) -> Result<(), PathEscapesRoot> {
+        // The root itself walks up to its nearest existing ancestor too: a root that
+        // hasn't been created yet (a perfectly normal first write/create_dir against a
+        // fresh `live()` root) must not make every operation fail as if it escaped.
+        let root = Self::resolve_nearest_existing_ancestor(&self.parent)
+            .await
+            .ok_or(PathEscapesRoot)?;
+        match Self::resolve_nearest_existing_ancestor(path_with_parent).await {
+            Some(resolved) if resolved.starts_with(&root) => Ok(()),
+            Some(_resolved) => Err(PathEscapesRoot),
+            None => Ok(()),
+        }
+    }
+
+    /// Walks up from `path` to the nearest ancestor that currently exists and
+    /// canonicalizes that ancestor, resolving any symlinks along the way. Returns
+    /// `None` if no ancestor of `path` exists at all.
+    async fn resolve_nearest_existing_ancestor(path: &str) -> Option<std::path::PathBuf> {
+        let mut candidate = std::path::PathBuf::from(path);
+        loop {
+            match fs::canonicalize(&candidate).await {
+                Ok(resolved) => return Some(resolved),
+                Err(_source) if candidate.pop() => {}
+                Err(_source) => return None,
+            }
+        }
+    }
+
+    /// Builds a sibling temporary path in the same directory as `path_with_parent`,
+    /// so the final `rename`/`hard_link` stays within one filesystem.
+    fn temp_path(path_with_parent: &str) -> String {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default();
+        format!("{path_with_parent}{TEMP_PATH_MARKER}{}-{nanos}", std::process::id())
+    }
+
+    async fn ensure_parent_dir(path: &str, path_with_parent: &str) -> Result<(), WriteError> {
+        let dir_path = std::path::Path::new(path_with_parent)
+            .parent()
+            .ok_or_else(|| std::io::Error::other("Could not get parent directory"))
+            .map_err(|source| WriteError {
                 path: path.to_string(),
-                source,
+                kind: WriteErrorKind::Io(source),
             })?;
-        file.write_all(content.as_bytes())
+        fs::create_dir_all(dir_path)
             .await
             .map_err(|source| WriteError {
                 path: path.to_string(),
-                source,
+                kind: WriteErrorKind::Io(source),
+            })
+    }
+
+    /// Writes `reader` to a fresh temporary file next to `path_with_parent` and
+    /// `fsync`s it, without touching the final path. Returns the temporary path.
+    async fn write_temp<R>(
+        path: &str,
+        path_with_parent: &str,
+        mut reader: R,
+    ) -> Result<String, WriteError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let tmp_path = Self::temp_path(path_with_parent);
+        let guard = TempFileGuard::new(tmp_path.clone());
+        let mut tmp_file = File::create(&tmp_path).await.map_err(|source| WriteError {
+            path: path.to_string(),
+            kind: WriteErrorKind::Io(source),
+        })?;
+        tokio::io::copy(&mut reader, &mut tmp_file)
+            .await
+            .map_err(|source| WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::Io(source),
+            })?;
+        tmp_file.flush().await.map_err(|source| WriteError {
+            path: path.to_string(),
+            kind: WriteErrorKind::Io(source),
+        })?;
+        tmp_file.sync_all().await.map_err(|source| WriteError {
+            path: path.to_string(),
+            kind: WriteErrorKind::Io(source),
+        })?;
+        Ok(guard.disarm())
+    }
+}
+
+#[async_trait]
+impl Files for ParentDirectory {
+    async fn read(&self, path: &str) -> Result<String, ReadError> {
+        let mut stream = self.read_stream(path).await?;
+        let mut content = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|source| ReadError {
+                path: path.to_string(),
+                kind: ReadErrorKind::Io(source),
+            })?;
+            content.extend_from_slice(&chunk);
+        }
+        String::from_utf8(content).map_err(|err| ReadError {
+            path: path.to_string(),
+            kind: ReadErrorKind::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, err)),
+        })
+    }
+
+    async fn write(&self, path: &str, content: &str) -> Result<(), WriteError> {
+        self.save_async_read(path, std::io::Cursor::new(content.as_bytes().to_vec()))
+            .await
+    }
+
+    async fn write_new(&self, path: &str, content: &str) -> Result<(), WriteError> {
+        self.save_async_read_new(path, std::io::Cursor::new(content.as_bytes().to_vec()))
+            .await
+    }
+
+    async fn read_stream(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = std::io::Result<Bytes>> + Send>>, ReadError> {
+        let path_with_parent = self.with_parent(path).map_err(|PathEscapesRoot| ReadError {
+            path: path.to_string(),
+            kind: ReadErrorKind::OutsideRoot,
+        })?;
+        self.ensure_resolved_within_root(&path_with_parent)
+            .await
+            .map_err(|PathEscapesRoot| ReadError {
+                path: path.to_string(),
+                kind: ReadErrorKind::OutsideRoot,
+            })?;
+        let file = File::open(&path_with_parent)
+            .await
+            .map_err(|source| ReadError {
+                path: path.to_string(),
+                kind: ReadErrorKind::Io(source),
+            })?;
+        Ok(Box::pin(ReaderStream::new(file)))
+    }
+
+    async fn save_async_read<R>(&self, path: &str, reader: R) -> Result<(), WriteError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let path_with_parent = self.with_parent(path).map_err(|PathEscapesRoot| WriteError {
+            path: path.to_string(),
+            kind: WriteErrorKind::OutsideRoot,
+        })?;
+        self.ensure_resolved_within_root(&path_with_parent)
+            .await
+            .map_err(|PathEscapesRoot| WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::OutsideRoot,
+            })?;
+        Self::ensure_parent_dir(path, &path_with_parent).await?;
+        let tmp_path = Self::write_temp(path, &path_with_parent, reader).await?;
+        let guard = TempFileGuard::new(tmp_path);
+        fs::rename(guard.path(), &path_with_parent)
+            .await
+            .map_err(|source| WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::Io(source),
             })?;
+        guard.disarm();
         Ok(())
     }
 
+    async fn save_async_read_new<R>(&self, path: &str, reader: R) -> Result<(), WriteError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let path_with_parent = self.with_parent(path).map_err(|PathEscapesRoot| WriteError {
+            path: path.to_string(),
+            kind: WriteErrorKind::OutsideRoot,
+        })?;
+        self.ensure_resolved_within_root(&path_with_parent)
+            .await
+            .map_err(|PathEscapesRoot| WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::OutsideRoot,
+            })?;
+        Self::ensure_parent_dir(path, &path_with_parent).await?;
+        let tmp_path = Self::write_temp(path, &path_with_parent, reader).await?;
+        let link_result = fs::hard_link(&tmp_path, &path_with_parent).await;
+        let _ = fs::remove_file(&tmp_path).await;
+        link_result.map_err(|source| {
+            if source.kind() == std::io::ErrorKind::AlreadyExists {
+                WriteError {
+                    path: path.to_string(),
+                    kind: WriteErrorKind::AlreadyExists,
+                }
+            } else {
+                WriteError {
+                    path: path.to_string(),
+                    kind: WriteErrorKind::Io(source),
+                }
+            }
+        })
+    }
+
     fn list(&self, expression: &str) -> Result<Vec<String>, ListError> {
+        let normalized =
+            Self::normalize_relative_path(expression).ok_or_else(|| ListError {
+                expression: expression.to_string(),
+                kind: ListErrorKind::OutsideRoot,
+            })?;
         let mut paths = vec![];
+        // `self.parent` may not exist yet (e.g. nothing has been written through this
+        // root), in which case the glob below matches nothing and there's nothing to
+        // canonicalize-and-compare against.
+        let root_canon = std::fs::canonicalize(&self.parent).ok();
 
         for path_buf_result in
-            glob::glob(&format!("{}/{expression}", self.parent)).map_err(|err| ListError {
+            glob::glob(&format!("{}/{normalized}", self.parent)).map_err(|err| ListError {
                 expression: expression.to_string(),
                 kind: ListErrorKind::ParseGlob(err),
             })?
@@ -232,12 +1023,1307 @@ impl Files for ParentDirectory {
                 expression: expression.to_string(),
                 kind: ListErrorKind::ReadPath(err),
             })?;
-            if path_buf.is_file() {
-                if let Some(path) = path_buf.as_path().to_str() {
-                    paths.push(path.to_string());
-                }
+            if !path_buf.is_file() {
+                continue;
+            }
+            // Refuse to leak a match only reachable by following a symlink that
+            // resolves outside the root (e.g. `root/escape -> /tmp/outside`), the same
+            // guarantee `ensure_resolved_within_root` gives every other operation.
+            if let Some(root_canon) = &root_canon
+                && let Ok(resolved) = path_buf.canonicalize()
+                && !resolved.starts_with(root_canon)
+            {
+                continue;
+            }
+            if let Some(path) = path_buf.as_path().to_str() {
+                paths.push(path.to_string());
             }
         }
         Ok(paths)
     }
+
+    async fn create_dir(&self, path: &str) -> Result<(), CreateDirError> {
+        let full_path = self.with_parent(path).map_err(|PathEscapesRoot| CreateDirError {
+            path: path.to_string(),
+            source: path_escapes_root_error(),
+        })?;
+        self.ensure_resolved_within_root(&full_path)
+            .await
+            .map_err(|PathEscapesRoot| CreateDirError {
+                path: path.to_string(),
+                source: path_escapes_root_error(),
+            })?;
+        fs::create_dir_all(full_path)
+            .await
+            .map_err(|source| CreateDirError {
+                path: path.to_string(),
+                source,
+            })
+    }
+
+    async fn remove_file(&self, path: &str, options: RemoveOptions) -> Result<(), RemoveError> {
+        let full_path = self.with_parent(path).map_err(|PathEscapesRoot| RemoveError {
+            path: path.to_string(),
+            source: path_escapes_root_error(),
+        })?;
+        self.ensure_resolved_within_root(&full_path)
+            .await
+            .map_err(|PathEscapesRoot| RemoveError {
+                path: path.to_string(),
+                source: path_escapes_root_error(),
+            })?;
+        match fs::remove_file(full_path).await {
+            Ok(()) => Ok(()),
+            Err(source)
+                if options.ignore_if_not_exists
+                    && source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                Ok(())
+            }
+            Err(source) => Err(RemoveError {
+                path: path.to_string(),
+                source,
+            }),
+        }
+    }
+
+    async fn remove_dir(&self, path: &str, options: RemoveOptions) -> Result<(), RemoveError> {
+        let full_path = self.with_parent(path).map_err(|PathEscapesRoot| RemoveError {
+            path: path.to_string(),
+            source: path_escapes_root_error(),
+        })?;
+        self.ensure_resolved_within_root(&full_path)
+            .await
+            .map_err(|PathEscapesRoot| RemoveError {
+                path: path.to_string(),
+                source: path_escapes_root_error(),
+            })?;
+        let result = if options.recursive {
+            fs::remove_dir_all(&full_path).await
+        } else {
+            fs::remove_dir(&full_path).await
+        };
+        match result {
+            Ok(()) => Ok(()),
+            Err(source)
+                if options.ignore_if_not_exists
+                    && source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                Ok(())
+            }
+            Err(source) => Err(RemoveError {
+                path: path.to_string(),
+                source,
+            }),
+        }
+    }
+
+    async fn copy(&self, from: &str, to: &str, options: CopyOptions) -> Result<(), CopyError> {
+        let to_copy_error = |PathEscapesRoot| CopyError {
+            from: from.to_string(),
+            to: to.to_string(),
+            source: path_escapes_root_error(),
+        };
+        let from_full = self.with_parent(from).map_err(to_copy_error)?;
+        let to_full = self.with_parent(to).map_err(to_copy_error)?;
+        self.ensure_resolved_within_root(&from_full)
+            .await
+            .map_err(to_copy_error)?;
+        self.ensure_resolved_within_root(&to_full)
+            .await
+            .map_err(to_copy_error)?;
+        if let Some(parent) = std::path::Path::new(&to_full).parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|source| CopyError {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    source,
+                })?;
+        }
+        if options.overwrite {
+            return fs::copy(&from_full, &to_full)
+                .await
+                .map(|_| ())
+                .map_err(|source| CopyError {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    source,
+                });
+        }
+        // `overwrite: false` needs the existence check and the create to be one
+        // atomic step; a separate `fs::metadata` + `fs::copy` would leave a window
+        // for a concurrent writer to create `to` in between and get silently
+        // overwritten. Copy into a sibling temp file, then `hard_link` it into place:
+        // like `save_async_read_new`, `hard_link` fails with `AlreadyExists` instead
+        // of clobbering, and the temp file is always removed afterward either way.
+        let tmp_path = Self::temp_path(&to_full);
+        let guard = TempFileGuard::new(tmp_path.clone());
+        fs::copy(&from_full, &tmp_path)
+            .await
+            .map_err(|source| CopyError {
+                from: from.to_string(),
+                to: to.to_string(),
+                source,
+            })?;
+        let link_result = fs::hard_link(&tmp_path, &to_full).await;
+        drop(guard);
+        link_result.map_err(|source| {
+            if source.kind() == std::io::ErrorKind::AlreadyExists {
+                CopyError {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    source: std::io::Error::new(
+                        std::io::ErrorKind::AlreadyExists,
+                        "destination already exists",
+                    ),
+                }
+            } else {
+                CopyError {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    source,
+                }
+            }
+        })
+    }
+
+    async fn rename(
+        &self,
+        from: &str,
+        to: &str,
+        options: RenameOptions,
+    ) -> Result<(), RenameError> {
+        let to_rename_error = |PathEscapesRoot| RenameError {
+            from: from.to_string(),
+            to: to.to_string(),
+            source: path_escapes_root_error(),
+        };
+        let from_full = self.with_parent(from).map_err(to_rename_error)?;
+        let to_full = self.with_parent(to).map_err(to_rename_error)?;
+        self.ensure_resolved_within_root(&from_full)
+            .await
+            .map_err(to_rename_error)?;
+        self.ensure_resolved_within_root(&to_full)
+            .await
+            .map_err(to_rename_error)?;
+        if let Some(parent) = std::path::Path::new(&to_full).parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|source| RenameError {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    source,
+                })?;
+        }
+        if options.overwrite {
+            return fs::rename(&from_full, &to_full)
+                .await
+                .map_err(|source| RenameError {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    source,
+                });
+        }
+        // `overwrite: false` can't use a separate `fs::metadata` existence check
+        // ahead of `fs::rename`: that leaves a window for a concurrent writer to
+        // create `to` in between and get silently overwritten. `hard_link` instead
+        // creates `to` from `from`'s contents and atomically fails with
+        // `AlreadyExists` rather than clobbering, the same technique
+        // `save_async_read_new` uses; removing `from` afterward completes the move.
+        match fs::hard_link(&from_full, &to_full).await {
+            Ok(()) => fs::remove_file(&from_full).await.map_err(|source| RenameError {
+                from: from.to_string(),
+                to: to.to_string(),
+                source,
+            }),
+            Err(source) if source.kind() == std::io::ErrorKind::AlreadyExists => {
+                Err(RenameError {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    source: std::io::Error::new(
+                        std::io::ErrorKind::AlreadyExists,
+                        "destination already exists",
+                    ),
+                })
+            }
+            Err(source) => Err(RenameError {
+                from: from.to_string(),
+                to: to.to_string(),
+                source,
+            }),
+        }
+    }
+
+    async fn metadata(&self, path: &str) -> Result<Metadata, MetadataError> {
+        let full_path = self.with_parent(path).map_err(|PathEscapesRoot| MetadataError {
+            path: path.to_string(),
+            source: path_escapes_root_error(),
+        })?;
+        self.ensure_resolved_within_root(&full_path)
+            .await
+            .map_err(|PathEscapesRoot| MetadataError {
+                path: path.to_string(),
+                source: path_escapes_root_error(),
+            })?;
+        let meta = fs::metadata(full_path).await.map_err(|source| MetadataError {
+            path: path.to_string(),
+            source,
+        })?;
+        let modified = meta.modified().map_err(|source| MetadataError {
+            path: path.to_string(),
+            source,
+        })?;
+        Ok(Metadata {
+            size: meta.len(),
+            modified,
+            is_file: meta.is_file(),
+            is_dir: meta.is_dir(),
+        })
+    }
+
+    async fn watch(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Vec<PathEvent>> + Send>>, WatchError> {
+        let path_with_parent = self.with_parent(path).map_err(|PathEscapesRoot| WatchError {
+            path: path.to_string(),
+            source: notify::Error::generic("path escapes the configured root"),
+        })?;
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            if let Ok(event) = event {
+                let _ = tx.send(event);
+            }
+        })
+        .map_err(|source| WatchError {
+            path: path.to_string(),
+            source,
+        })?;
+        notify::Watcher::watch(
+            &mut watcher,
+            std::path::Path::new(&path_with_parent),
+            notify::RecursiveMode::Recursive,
+        )
+        .map_err(|source| WatchError {
+            path: path.to_string(),
+            source,
+        })?;
+        Ok(Box::pin(DebouncedWatch {
+            _watcher: watcher,
+            events: rx,
+            root: self.parent.clone(),
+            pending: Vec::new(),
+            debounce: std::time::Duration::from_millis(200),
+            timer: None,
+        }))
+    }
+}
+
+/// A [`Stream`] of debounced [`PathEvent`] batches backing [`ParentDirectory::watch`].
+///
+/// Raw `notify` events arrive one at a time; this buffers them into `pending` and only
+/// yields once `debounce` has elapsed without a new event, so a burst of writes collapses
+/// into a single batch.
+struct DebouncedWatch {
+    _watcher: notify::RecommendedWatcher,
+    events: tokio::sync::mpsc::UnboundedReceiver<notify::Event>,
+    root: String,
+    pending: Vec<PathEvent>,
+    debounce: std::time::Duration,
+    timer: Option<Pin<Box<tokio::time::Sleep>>>,
+}
+
+impl Stream for DebouncedWatch {
+    type Item = Vec<PathEvent>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::future::Future;
+        use std::task::Poll;
+
+        let this = self.get_mut();
+        loop {
+            match this.events.poll_recv(cx) {
+                Poll::Ready(Some(event)) => {
+                    for changed_path in &event.paths {
+                        if let Some(relative) = relative_to_root(&this.root, changed_path)
+                            && !relative.contains(TEMP_PATH_MARKER)
+                        {
+                            this.pending.push(path_event_from_notify(&event.kind, relative));
+                        }
+                    }
+                    this.timer = Some(Box::pin(tokio::time::sleep(this.debounce)));
+                    continue;
+                }
+                Poll::Ready(None) => {
+                    return if this.pending.is_empty() {
+                        Poll::Ready(None)
+                    } else {
+                        Poll::Ready(Some(std::mem::take(&mut this.pending)))
+                    };
+                }
+                Poll::Pending => {}
+            }
+            if let Some(timer) = this.timer.as_mut()
+                && timer.as_mut().poll(cx).is_ready()
+            {
+                this.timer = None;
+                if !this.pending.is_empty() {
+                    return Poll::Ready(Some(std::mem::take(&mut this.pending)));
+                }
+                continue;
+            }
+            return Poll::Pending;
+        }
+    }
+}
+
+/// Maps a `notify` event kind to the corresponding [`PathEvent`] variant.
+fn path_event_from_notify(kind: &notify::EventKind, relative: String) -> PathEvent {
+    match kind {
+        notify::EventKind::Create(_) => PathEvent::Created(relative),
+        notify::EventKind::Remove(_) => PathEvent::Removed(relative),
+        _ => PathEvent::Modified(relative),
+    }
+}
+
+/// Translates an absolute OS path reported by `notify` back into a root-relative string
+/// with no root prefix (unlike the local backend's [`Files::list`]). Falls back to a non-canonicalized comparison
+/// for removed paths, which no longer exist to canonicalize.
+fn relative_to_root(root: &str, changed_path: &std::path::Path) -> Option<String> {
+    let root_canon = std::fs::canonicalize(root).ok();
+    for candidate_root in root_canon.as_deref().into_iter().chain([std::path::Path::new(root)]) {
+        if let Ok(relative) = changed_path.strip_prefix(candidate_root) {
+            return relative.to_str().map(ToString::to_string);
+        }
+        if let Ok(changed_canon) = changed_path.canonicalize()
+            && let Ok(relative) = changed_canon.strip_prefix(candidate_root)
+        {
+            return relative.to_str().map(ToString::to_string);
+        }
+    }
+    None
+}
+
+#[derive(Clone, Default)]
+struct InMemory {
+    files: Arc<RwLock<HashMap<String, Vec<u8>>>>,
+}
+
+impl InMemory {
+    fn not_found(path: &str) -> ReadError {
+        ReadError {
+            path: path.to_string(),
+            kind: ReadErrorKind::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "file not found",
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl Files for InMemory {
+    async fn read(&self, path: &str) -> Result<String, ReadError> {
+        let files = self.files.read().expect("in-memory files lock poisoned");
+        let content = files.get(path).ok_or_else(|| Self::not_found(path))?;
+        String::from_utf8(content.clone()).map_err(|err| ReadError {
+            path: path.to_string(),
+            kind: ReadErrorKind::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, err)),
+        })
+    }
+
+    async fn write(&self, path: &str, content: &str) -> Result<(), WriteError> {
+        // Creating implied parent "dirs" is a no-op; keys are flat strings.
+        let mut files = self.files.write().expect("in-memory files lock poisoned");
+        files.insert(path.to_string(), content.as_bytes().to_vec());
+        Ok(())
+    }
+
+    async fn write_new(&self, path: &str, content: &str) -> Result<(), WriteError> {
+        let mut files = self.files.write().expect("in-memory files lock poisoned");
+        if files.contains_key(path) {
+            return Err(WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::AlreadyExists,
+            });
+        }
+        files.insert(path.to_string(), content.as_bytes().to_vec());
+        Ok(())
+    }
+
+    async fn read_stream(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = std::io::Result<Bytes>> + Send>>, ReadError> {
+        let content = {
+            let files = self.files.read().expect("in-memory files lock poisoned");
+            files
+                .get(path)
+                .cloned()
+                .ok_or_else(|| Self::not_found(path))?
+        };
+        Ok(Box::pin(futures::stream::once(async move {
+            Ok(Bytes::from(content))
+        })))
+    }
+
+    async fn save_async_read<R>(&self, path: &str, mut reader: R) -> Result<(), WriteError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let mut content = Vec::new();
+        reader
+            .read_to_end(&mut content)
+            .await
+            .map_err(|source| WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::Io(source),
+            })?;
+        let mut files = self.files.write().expect("in-memory files lock poisoned");
+        files.insert(path.to_string(), content);
+        Ok(())
+    }
+
+    async fn save_async_read_new<R>(&self, path: &str, mut reader: R) -> Result<(), WriteError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let mut content = Vec::new();
+        reader
+            .read_to_end(&mut content)
+            .await
+            .map_err(|source| WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::Io(source),
+            })?;
+        let mut files = self.files.write().expect("in-memory files lock poisoned");
+        if files.contains_key(path) {
+            return Err(WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::AlreadyExists,
+            });
+        }
+        files.insert(path.to_string(), content);
+        Ok(())
+    }
+
+    fn list(&self, expression: &str) -> Result<Vec<String>, ListError> {
+        let pattern = glob::Pattern::new(expression).map_err(|err| ListError {
+            expression: expression.to_string(),
+            kind: ListErrorKind::ParseGlob(err),
+        })?;
+        let files = self.files.read().expect("in-memory files lock poisoned");
+        Ok(files
+            .keys()
+            .filter(|key| pattern.matches(key))
+            .cloned()
+            .collect())
+    }
+
+    async fn create_dir(&self, _path: &str) -> Result<(), CreateDirError> {
+        // Keys are flat strings; there is no real directory to create.
+        Ok(())
+    }
+
+    async fn remove_file(&self, path: &str, options: RemoveOptions) -> Result<(), RemoveError> {
+        let mut files = self.files.write().expect("in-memory files lock poisoned");
+        if files.remove(path).is_none() && !options.ignore_if_not_exists {
+            return Err(RemoveError {
+                path: path.to_string(),
+                source: std::io::Error::new(std::io::ErrorKind::NotFound, "file not found"),
+            });
+        }
+        Ok(())
+    }
+
+    async fn remove_dir(&self, path: &str, options: RemoveOptions) -> Result<(), RemoveError> {
+        let prefix = format!("{path}/");
+        let mut files = self.files.write().expect("in-memory files lock poisoned");
+        let matching: Vec<String> = files
+            .keys()
+            .filter(|key| key.starts_with(&prefix))
+            .cloned()
+            .collect();
+        if matching.is_empty() && !options.ignore_if_not_exists {
+            return Err(RemoveError {
+                path: path.to_string(),
+                source: std::io::Error::new(std::io::ErrorKind::NotFound, "directory not found"),
+            });
+        }
+        if !options.recursive && !matching.is_empty() {
+            return Err(RemoveError {
+                path: path.to_string(),
+                source: std::io::Error::other("directory not empty"),
+            });
+        }
+        for key in matching {
+            files.remove(&key);
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, from: &str, to: &str, options: CopyOptions) -> Result<(), CopyError> {
+        let mut files = self.files.write().expect("in-memory files lock poisoned");
+        let content = files.get(from).cloned().ok_or_else(|| CopyError {
+            from: from.to_string(),
+            to: to.to_string(),
+            source: std::io::Error::new(std::io::ErrorKind::NotFound, "source not found"),
+        })?;
+        if !options.overwrite && files.contains_key(to) {
+            return Err(CopyError {
+                from: from.to_string(),
+                to: to.to_string(),
+                source: std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    "destination already exists",
+                ),
+            });
+        }
+        files.insert(to.to_string(), content);
+        Ok(())
+    }
+
+    async fn rename(
+        &self,
+        from: &str,
+        to: &str,
+        options: RenameOptions,
+    ) -> Result<(), RenameError> {
+        let mut files = self.files.write().expect("in-memory files lock poisoned");
+        if !options.overwrite && files.contains_key(to) {
+            return Err(RenameError {
+                from: from.to_string(),
+                to: to.to_string(),
+                source: std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    "destination already exists",
+                ),
+            });
+        }
+        let content = files.remove(from).ok_or_else(|| RenameError {
+            from: from.to_string(),
+            to: to.to_string(),
+            source: std::io::Error::new(std::io::ErrorKind::NotFound, "source not found"),
+        })?;
+        files.insert(to.to_string(), content);
+        Ok(())
+    }
+
+    async fn metadata(&self, path: &str) -> Result<Metadata, MetadataError> {
+        let files = self.files.read().expect("in-memory files lock poisoned");
+        if let Some(content) = files.get(path) {
+            return Ok(Metadata {
+                size: content.len() as u64,
+                modified: std::time::SystemTime::now(),
+                is_file: true,
+                is_dir: false,
+            });
+        }
+        let prefix = format!("{path}/");
+        if files.keys().any(|key| key.starts_with(&prefix)) {
+            return Ok(Metadata {
+                size: 0,
+                modified: std::time::SystemTime::now(),
+                is_file: false,
+                is_dir: true,
+            });
+        }
+        Err(MetadataError {
+            path: path.to_string(),
+            source: std::io::Error::new(std::io::ErrorKind::NotFound, "path not found"),
+        })
+    }
+
+    async fn watch(
+        &self,
+        _path: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Vec<PathEvent>> + Send>>, WatchError> {
+        // There's no real filesystem backing this store for a notifier to watch, so
+        // callers exercising watch-mode logic against `memory()` see a stream that never
+        // yields rather than a misleading synthetic event.
+        Ok(Box::pin(futures::stream::pending()))
+    }
+}
+
+/// `Files` over any [`object_store::ObjectStore`], backing the feature-gated [`s3`]/[`gcs`]/
+/// [`azure`] constructors. `read`/`write` map to GET/PUT, `list` to a prefix LIST filtered
+/// by the glob pattern, and `copy`/`rename`/`remove_file` to their object-store equivalents.
+///
+/// Every path is resolved under `prefix`, the same role [`ParentDirectory::parent`] plays
+/// for the local backend, so several logical roots can share one bucket.
+#[cfg(any(feature = "s3", feature = "gcs", feature = "azure"))]
+struct ObjectStoreFiles {
+    store: Arc<dyn object_store::ObjectStore>,
+    prefix: object_store::path::Path,
+}
+
+#[cfg(any(feature = "s3", feature = "gcs", feature = "azure"))]
+impl ObjectStoreFiles {
+    fn new(store: Arc<dyn object_store::ObjectStore>, prefix: String) -> Self {
+        Self {
+            store,
+            prefix: object_store::path::Path::from(prefix),
+        }
+    }
+
+    fn object_path(&self, path: &str) -> object_store::path::Path {
+        object_store::path::Path::from(format!("{}/{path}", self.prefix))
+    }
+
+    /// Strips `prefix` back off a location reported by the store, so callers see the same
+    /// root-relative paths they passed in, consistent with [`Files::list`]'s output.
+    fn relative_path(&self, location: &object_store::path::Path) -> String {
+        location
+            .prefix_match(&self.prefix)
+            .map(|parts| parts.collect::<object_store::path::Path>().to_string())
+            .unwrap_or_else(|| location.to_string())
+    }
+
+    async fn put(&self, path: &str, content: Vec<u8>) -> Result<(), WriteError> {
+        self.store
+            .put(&self.object_path(path), content.into())
+            .await
+            .map(|_| ())
+            .map_err(|source| WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::Io(std::io::Error::other(source)),
+            })
+    }
+
+    async fn put_new(&self, path: &str, content: Vec<u8>) -> Result<(), WriteError> {
+        let options = object_store::PutOptions {
+            mode: object_store::PutMode::Create,
+            ..Default::default()
+        };
+        self.store
+            .put_opts(&self.object_path(path), content.into(), options)
+            .await
+            .map(|_| ())
+            .map_err(|source| match source {
+                object_store::Error::AlreadyExists { .. } => WriteError {
+                    path: path.to_string(),
+                    kind: WriteErrorKind::AlreadyExists,
+                },
+                source => WriteError {
+                    path: path.to_string(),
+                    kind: WriteErrorKind::Io(std::io::Error::other(source)),
+                },
+            })
+    }
+}
+
+#[cfg(any(feature = "s3", feature = "gcs", feature = "azure"))]
+use object_store::ObjectStoreExt as _;
+
+#[cfg(any(feature = "s3", feature = "gcs", feature = "azure"))]
+#[async_trait]
+impl Files for ObjectStoreFiles {
+    async fn read(&self, path: &str) -> Result<String, ReadError> {
+        let result = self
+            .store
+            .get(&self.object_path(path))
+            .await
+            .map_err(|source| ReadError {
+                path: path.to_string(),
+                kind: ReadErrorKind::Io(std::io::Error::other(source)),
+            })?;
+        let bytes = result.bytes().await.map_err(|source| ReadError {
+            path: path.to_string(),
+            kind: ReadErrorKind::Io(std::io::Error::other(source)),
+        })?;
+        String::from_utf8(bytes.to_vec()).map_err(|err| ReadError {
+            path: path.to_string(),
+            kind: ReadErrorKind::Io(std::io::Error::other(err)),
+        })
+    }
+
+    async fn write(&self, path: &str, content: &str) -> Result<(), WriteError> {
+        self.put(path, content.as_bytes().to_vec()).await
+    }
+
+    async fn write_new(&self, path: &str, content: &str) -> Result<(), WriteError> {
+        self.put_new(path, content.as_bytes().to_vec()).await
+    }
+
+    async fn read_stream(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = std::io::Result<Bytes>> + Send>>, ReadError> {
+        let result = self
+            .store
+            .get(&self.object_path(path))
+            .await
+            .map_err(|source| ReadError {
+                path: path.to_string(),
+                kind: ReadErrorKind::Io(std::io::Error::other(source)),
+            })?;
+        Ok(Box::pin(
+            result
+                .into_stream()
+                .map(|chunk| chunk.map_err(std::io::Error::other)),
+        ))
+    }
+
+    async fn save_async_read<R>(&self, path: &str, mut reader: R) -> Result<(), WriteError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let mut content = Vec::new();
+        reader
+            .read_to_end(&mut content)
+            .await
+            .map_err(|source| WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::Io(source),
+            })?;
+        self.put(path, content).await
+    }
+
+    async fn save_async_read_new<R>(&self, path: &str, mut reader: R) -> Result<(), WriteError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let mut content = Vec::new();
+        reader
+            .read_to_end(&mut content)
+            .await
+            .map_err(|source| WriteError {
+                path: path.to_string(),
+                kind: WriteErrorKind::Io(source),
+            })?;
+        self.put_new(path, content).await
+    }
+
+    /// Bridges `list`'s synchronous signature (shared with the local/in-memory backends)
+    /// to the LIST request a remote object store requires.
+    ///
+    /// `tokio::task::block_in_place` would panic when the caller is on a current-thread
+    /// runtime, which defeats the goal of `live()` callers working unchanged against
+    /// remote storage. Instead, the LIST runs on a dedicated OS thread with its own
+    /// single-threaded runtime, so this never depends on the calling thread's runtime
+    /// flavor (or on there being a Tokio runtime on the calling thread at all).
+    fn list(&self, expression: &str) -> Result<Vec<String>, ListError> {
+        use futures::TryStreamExt;
+
+        let pattern = glob::Pattern::new(expression).map_err(|err| ListError {
+            expression: expression.to_string(),
+            kind: ListErrorKind::ParseGlob(err),
+        })?;
+        let store = Arc::clone(&self.store);
+        let prefix = self.prefix.clone();
+        let locations: Vec<object_store::path::Path> = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("building a current-thread runtime for the list bridge thread");
+            runtime.block_on(async move {
+                store
+                    .list(Some(&prefix))
+                    .map_ok(|meta| meta.location)
+                    .try_collect()
+                    .await
+            })
+        })
+        .join()
+        .expect("list bridge thread panicked")
+        .map_err(|source| ListError {
+            expression: expression.to_string(),
+            kind: ListErrorKind::Io(std::io::Error::other(source)),
+        })?;
+        Ok(locations
+            .into_iter()
+            .map(|location| self.relative_path(&location))
+            .filter(|relative| pattern.matches(relative))
+            .collect())
+    }
+
+    async fn create_dir(&self, _path: &str) -> Result<(), CreateDirError> {
+        // Object stores have no real directories; keys imply their own "path".
+        Ok(())
+    }
+
+    async fn remove_file(&self, path: &str, options: RemoveOptions) -> Result<(), RemoveError> {
+        match self.store.delete(&self.object_path(path)).await {
+            Ok(()) => Ok(()),
+            Err(object_store::Error::NotFound { .. }) if options.ignore_if_not_exists => Ok(()),
+            Err(source) => Err(RemoveError {
+                path: path.to_string(),
+                source: std::io::Error::other(source),
+            }),
+        }
+    }
+
+    async fn remove_dir(&self, path: &str, options: RemoveOptions) -> Result<(), RemoveError> {
+        use futures::TryStreamExt;
+
+        let location = self.object_path(path);
+        let matches: Vec<object_store::path::Path> = self
+            .store
+            .list(Some(&location))
+            .map_ok(|meta| meta.location)
+            .try_collect()
+            .await
+            .map_err(|source| RemoveError {
+                path: path.to_string(),
+                source: std::io::Error::other(source),
+            })?;
+        if matches.is_empty() && !options.ignore_if_not_exists {
+            return Err(RemoveError {
+                path: path.to_string(),
+                source: std::io::Error::new(std::io::ErrorKind::NotFound, "directory not found"),
+            });
+        }
+        if !options.recursive && !matches.is_empty() {
+            return Err(RemoveError {
+                path: path.to_string(),
+                source: std::io::Error::other("directory not empty"),
+            });
+        }
+        for location in matches {
+            self.store
+                .delete(&location)
+                .await
+                .map_err(|source| RemoveError {
+                    path: path.to_string(),
+                    source: std::io::Error::other(source),
+                })?;
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, from: &str, to: &str, options: CopyOptions) -> Result<(), CopyError> {
+        let from_location = self.object_path(from);
+        let to_location = self.object_path(to);
+        let result = if options.overwrite {
+            self.store.copy(&from_location, &to_location).await
+        } else {
+            self.store
+                .copy_if_not_exists(&from_location, &to_location)
+                .await
+        };
+        result.map_err(|source| CopyError {
+            from: from.to_string(),
+            to: to.to_string(),
+            source: std::io::Error::other(source),
+        })
+    }
+
+    async fn rename(
+        &self,
+        from: &str,
+        to: &str,
+        options: RenameOptions,
+    ) -> Result<(), RenameError> {
+        let from_location = self.object_path(from);
+        let to_location = self.object_path(to);
+        let result = if options.overwrite {
+            self.store.rename(&from_location, &to_location).await
+        } else {
+            self.store
+                .rename_if_not_exists(&from_location, &to_location)
+                .await
+        };
+        result.map_err(|source| RenameError {
+            from: from.to_string(),
+            to: to.to_string(),
+            source: std::io::Error::other(source),
+        })
+    }
+
+    async fn metadata(&self, path: &str) -> Result<Metadata, MetadataError> {
+        let meta = self
+            .store
+            .head(&self.object_path(path))
+            .await
+            .map_err(|source| MetadataError {
+                path: path.to_string(),
+                source: std::io::Error::other(source),
+            })?;
+        let modified = std::time::UNIX_EPOCH
+            + std::time::Duration::from_millis(meta.last_modified.timestamp_millis().max(0) as u64);
+        Ok(Metadata {
+            size: meta.size,
+            modified,
+            is_file: true,
+            is_dir: false,
+        })
+    }
+
+    async fn watch(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Vec<PathEvent>> + Send>>, WatchError> {
+        // Remote object stores have no push-notification API this crate can uniformly
+        // target; local/in-memory are the backends `watch` supports today.
+        Err(WatchError {
+            path: path.to_string(),
+            source: notify::Error::generic(
+                "watch is not supported by object-storage backends (s3/gcs/azure)",
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a fresh, uniquely-named directory under the OS temp dir for a single
+    /// test, so parallel `#[tokio::test]`s never share a root.
+    fn temp_root(name: &str) -> String {
+        let dir = std::env::temp_dir().join(format!(
+            "filez-test-{name}-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_nanos())
+                .unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&dir).expect("creating test root");
+        dir.to_str().expect("test root path is valid UTF-8").to_string()
+    }
+
+    /// Reserves a unique path under the OS temp dir without creating it, for tests
+    /// exercising behavior against a root that doesn't exist yet.
+    fn uncreated_temp_root(name: &str) -> String {
+        let dir = std::env::temp_dir().join(format!(
+            "filez-test-{name}-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_nanos())
+                .unwrap_or_default()
+        ));
+        assert!(!dir.exists());
+        dir.to_str().expect("test root path is valid UTF-8").to_string()
+    }
+
+    #[tokio::test]
+    async fn live_write_and_create_dir_succeed_against_a_not_yet_created_root() {
+        let root = uncreated_temp_root("live-fresh-root");
+        let files = live(root.clone());
+
+        files.write("a.txt", "hello").await.unwrap();
+        files.create_dir("sub").await.unwrap();
+        assert_eq!(files.read("a.txt").await.unwrap(), "hello");
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[tokio::test]
+    async fn memory_write_then_read_roundtrips() {
+        let files = memory();
+        files.write("a.txt", "hello").await.unwrap();
+        assert_eq!(files.read("a.txt").await.unwrap(), "hello");
+    }
+
+    #[tokio::test]
+    async fn memory_read_missing_file_errors() {
+        let files = memory();
+        let err = files.read("missing.txt").await.unwrap_err();
+        assert!(matches!(err.kind, ReadErrorKind::Io(_)));
+    }
+
+    #[tokio::test]
+    async fn memory_write_new_rejects_existing_file() {
+        let files = memory();
+        files.write_new("a.txt", "hello").await.unwrap();
+        let err = files.write_new("a.txt", "again").await.unwrap_err();
+        assert!(matches!(err.kind, WriteErrorKind::AlreadyExists));
+    }
+
+    #[tokio::test]
+    async fn memory_list_matches_glob() {
+        let files = memory();
+        files.write("dir/a.txt", "a").await.unwrap();
+        files.write("dir/b.log", "b").await.unwrap();
+        files.write("other/c.txt", "c").await.unwrap();
+        let mut matches = files.list("dir/*.txt").unwrap();
+        matches.sort();
+        assert_eq!(matches, vec!["dir/a.txt".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn memory_remove_file_ignore_if_not_exists() {
+        let files = memory();
+        let options = RemoveOptions {
+            ignore_if_not_exists: true,
+            ..Default::default()
+        };
+        files.remove_file("missing.txt", options).await.unwrap();
+
+        let err = files
+            .remove_file("missing.txt", RemoveOptions::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.source.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[tokio::test]
+    async fn memory_remove_dir_requires_recursive_when_not_empty() {
+        let files = memory();
+        files.write("dir/a.txt", "a").await.unwrap();
+
+        let err = files
+            .remove_dir("dir", RemoveOptions::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.source.kind(), std::io::ErrorKind::Other);
+
+        let options = RemoveOptions {
+            recursive: true,
+            ..Default::default()
+        };
+        files.remove_dir("dir", options).await.unwrap();
+        assert!(files.list("dir/*").unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn memory_copy_preserves_source_and_rejects_existing_destination_without_overwrite() {
+        let files = memory();
+        files.write("from.txt", "content").await.unwrap();
+        files.copy("from.txt", "to.txt", CopyOptions::default()).await.unwrap();
+        assert_eq!(files.read("from.txt").await.unwrap(), "content");
+        assert_eq!(files.read("to.txt").await.unwrap(), "content");
+
+        let err = files
+            .copy("from.txt", "to.txt", CopyOptions::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.source.kind(), std::io::ErrorKind::AlreadyExists);
+    }
+
+    #[tokio::test]
+    async fn memory_rename_moves_source_to_destination() {
+        let files = memory();
+        files.write("from.txt", "content").await.unwrap();
+        files
+            .rename("from.txt", "to.txt", RenameOptions::default())
+            .await
+            .unwrap();
+        assert!(files.read("from.txt").await.is_err());
+        assert_eq!(files.read("to.txt").await.unwrap(), "content");
+    }
+
+    #[tokio::test]
+    async fn memory_metadata_detects_implied_directories() {
+        let files = memory();
+        files.write("dir/a.txt", "a").await.unwrap();
+
+        let file_meta = files.metadata("dir/a.txt").await.unwrap();
+        assert!(file_meta.is_file);
+        assert!(!file_meta.is_dir);
+
+        let dir_meta = files.metadata("dir").await.unwrap();
+        assert!(dir_meta.is_dir);
+        assert!(!dir_meta.is_file);
+
+        assert!(files.metadata("missing").await.is_err());
+    }
+
+    /// An `AsyncRead` that always fails, for exercising `save_async_read`'s cleanup of
+    /// its temporary file when the reader errors mid-copy.
+    struct FailingReader;
+
+    impl AsyncRead for FailingReader {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+            _buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> std::task::Poll<std::io::Result<()>> {
+            std::task::Poll::Ready(Err(std::io::Error::other("simulated read failure")))
+        }
+    }
+
+    #[tokio::test]
+    async fn live_save_async_read_cleans_up_temp_file_on_reader_failure() {
+        let root = temp_root("live-write-cleanup");
+        let files = live(root.clone());
+
+        let err = files
+            .save_async_read("a.txt", FailingReader)
+            .await
+            .unwrap_err();
+        assert!(matches!(err.kind, WriteErrorKind::Io(_)));
+
+        let leftovers: Vec<_> = std::fs::read_dir(&root)
+            .unwrap()
+            .filter_map(Result::ok)
+            .collect();
+        assert!(
+            leftovers.is_empty(),
+            "a failed write left files behind: {leftovers:?}"
+        );
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[tokio::test]
+    async fn live_write_then_read_roundtrips() {
+        let root = temp_root("live-roundtrip");
+        let files = live(root.clone());
+        files.write("a.txt", "hello").await.unwrap();
+        assert_eq!(files.read("a.txt").await.unwrap(), "hello");
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[tokio::test]
+    async fn live_create_dir_remove_dir_copy_rename_metadata() {
+        let root = temp_root("live-ops");
+        let files = live(root.clone());
+
+        files.create_dir("nested/dir").await.unwrap();
+        files.write("nested/dir/a.txt", "content").await.unwrap();
+
+        files
+            .copy(
+                "nested/dir/a.txt",
+                "nested/dir/b.txt",
+                CopyOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(files.read("nested/dir/b.txt").await.unwrap(), "content");
+
+        files
+            .rename(
+                "nested/dir/b.txt",
+                "nested/dir/c.txt",
+                RenameOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert!(files.read("nested/dir/b.txt").await.is_err());
+        assert_eq!(files.read("nested/dir/c.txt").await.unwrap(), "content");
+
+        let meta = files.metadata("nested/dir/a.txt").await.unwrap();
+        assert!(meta.is_file);
+
+        files
+            .remove_file("nested/dir/c.txt", RemoveOptions::default())
+            .await
+            .unwrap();
+
+        let options = RemoveOptions {
+            recursive: true,
+            ..Default::default()
+        };
+        files.remove_dir("nested", options).await.unwrap();
+        assert!(files.metadata("nested").await.is_err());
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[tokio::test]
+    async fn live_copy_without_overwrite_rejects_existing_destination() {
+        let root = temp_root("live-copy-no-overwrite");
+        let files = live(root.clone());
+        files.write("from.txt", "content").await.unwrap();
+        files.write("to.txt", "existing").await.unwrap();
+
+        let err = files
+            .copy("from.txt", "to.txt", CopyOptions::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.source.kind(), std::io::ErrorKind::AlreadyExists);
+        assert_eq!(files.read("to.txt").await.unwrap(), "existing");
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[tokio::test]
+    async fn live_rename_without_overwrite_rejects_existing_destination() {
+        let root = temp_root("live-rename-no-overwrite");
+        let files = live(root.clone());
+        files.write("from.txt", "content").await.unwrap();
+        files.write("to.txt", "existing").await.unwrap();
+
+        let err = files
+            .rename("from.txt", "to.txt", RenameOptions::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.source.kind(), std::io::ErrorKind::AlreadyExists);
+        assert_eq!(files.read("from.txt").await.unwrap(), "content");
+        assert_eq!(files.read("to.txt").await.unwrap(), "existing");
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[tokio::test]
+    async fn live_rejects_lexical_escape_above_root() {
+        let root = temp_root("live-lexical-jail");
+        let files = live(root.clone());
+
+        let err = files.read("../escape.txt").await.unwrap_err();
+        assert!(matches!(err.kind, ReadErrorKind::OutsideRoot));
+
+        let err = files
+            .write("../escape.txt", "pwned")
+            .await
+            .unwrap_err();
+        assert!(matches!(err.kind, WriteErrorKind::OutsideRoot));
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[tokio::test]
+    async fn live_rejects_symlink_escape_on_write() {
+        let root = temp_root("live-symlink-jail");
+        let outside = temp_root("live-symlink-jail-outside");
+        std::os::unix::fs::symlink(&outside, format!("{root}/escape")).unwrap();
+
+        let files = live(root.clone());
+        let err = files.write("escape/evil.txt", "pwned").await.unwrap_err();
+        assert!(matches!(err.kind, WriteErrorKind::OutsideRoot));
+        assert!(!std::path::Path::new(&outside).join("evil.txt").exists());
+
+        std::fs::remove_dir_all(&root).ok();
+        std::fs::remove_dir_all(&outside).ok();
+    }
+
+    #[tokio::test]
+    async fn live_list_does_not_leak_entries_through_a_symlink_escape() {
+        let root = temp_root("live-list-symlink-jail");
+        let outside = temp_root("live-list-symlink-jail-outside");
+        std::fs::write(format!("{outside}/secret.txt"), "secret").unwrap();
+        std::os::unix::fs::symlink(&outside, format!("{root}/escape")).unwrap();
+
+        let files = live(root.clone());
+        let matches = files.list("escape/*.txt").unwrap();
+        assert!(
+            matches.is_empty(),
+            "list leaked entries from outside the root: {matches:?}"
+        );
+
+        std::fs::remove_dir_all(&root).ok();
+        std::fs::remove_dir_all(&outside).ok();
+    }
+
+    #[tokio::test]
+    async fn live_watch_filters_internal_temp_files() {
+        let root = temp_root("live-watch");
+        let files = live(root.clone());
+        let mut stream = files.watch(".").await.unwrap();
+
+        files.write("new.txt", "hello").await.unwrap();
+
+        let batch = tokio::time::timeout(std::time::Duration::from_secs(2), stream.next())
+            .await
+            .expect("watch should yield a batch")
+            .expect("stream should not end");
+        for event in &batch {
+            let path = match event {
+                PathEvent::Created(path) | PathEvent::Modified(path) | PathEvent::Removed(path) => {
+                    path
+                }
+            };
+            assert!(!path.contains(TEMP_PATH_MARKER));
+        }
+
+        std::fs::remove_dir_all(&root).ok();
+    }
 }